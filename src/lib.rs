@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"),"/README.md"))]
 
+use core::marker::PhantomData;
+
 #[cfg(feature = "critical-section")]
 use critical_section::with;
 #[cfg(not(feature = "critical-section"))]
@@ -9,6 +11,7 @@ fn with<R>(f: impl FnOnce(()) -> R) -> R {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DhtError<DeviceError> {
     /// Initial handshake with the sensor was unsuccessful. Make sure all physical connections are working, individual reads of the sensor are seperated by at least 2 seconds and the pin state is high while idle
     Handshake,
@@ -16,6 +19,10 @@ pub enum DhtError<DeviceError> {
     Timeout(Microseconds),
     /// The checksum of the read data does not match with the provided checksum
     Checksum { correct: u8, actual: u8 },
+    /// `read()` was called before the configured minimum interval elapsed and no
+    /// cached reading was available to return. `remaining` is the time left
+    /// until the sensor may be queried again.
+    TooSoon { remaining: Microseconds },
     /// While setting the pin state the DeviceError occured
     DeviceError(DeviceError),
 }
@@ -36,6 +43,11 @@ where
                 f,
                 "Checksum validation failed. Correct: {correct}, Actual: {actual}"
             ),
+            DhtError::TooSoon { remaining } => write!(
+                f,
+                "Read attempted too soon, {} microseconds remaining until the sensor may be queried again",
+                remaining.0
+            ),
             DhtError::DeviceError(device_error) => write!(f, "DeviceError: {device_error}"),
         }
     }
@@ -50,6 +62,21 @@ impl<DeviceError> From<DeviceError> for DhtError<DeviceError> {
     }
 }
 
+impl DhtError<core::convert::Infallible> {
+    /// Widen an error from a device-independent decode (which can never carry a
+    /// [`DeviceError`](DhtError::DeviceError)) into one parameterized over an
+    /// arbitrary device error.
+    fn widen<DeviceError>(self) -> DhtError<DeviceError> {
+        match self {
+            DhtError::Handshake => DhtError::Handshake,
+            DhtError::Timeout(us) => DhtError::Timeout(us),
+            DhtError::Checksum { correct, actual } => DhtError::Checksum { correct, actual },
+            DhtError::TooSoon { remaining } => DhtError::TooSoon { remaining },
+            DhtError::DeviceError(never) => match never {},
+        }
+    }
+}
+
 /// Represents a GPIO pin capable of reading and setting the voltage level
 pub trait IOPin {
     type DeviceError;
@@ -64,6 +91,7 @@ pub trait IOPin {
 /// The std::duration::Duration which could also be used here is a much larger type in order to accomodate much
 /// bigger time spans, which may impact performance, code size and stack usage.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Microseconds(pub u32);
 
 /// Represents a timer with microsecond resolution
@@ -73,49 +101,321 @@ pub trait MicroTimer {
     fn now(&self) -> Microseconds;
 }
 
+/// Distinguishes the members of the DHTxx family that share the single-wire
+/// handshake but differ in their wake-up timing and 40-bit frame layout.
+///
+/// The edge-capture and checksum core in [`Dht22::read`] is identical for every
+/// sensor; only the handshake pulse length and the final byte-to-reading
+/// conversion are selected through this trait.
+pub trait SensorKind {
+    /// Microseconds the MCU has to pull the line low to wake the sensor.
+    /// The DHT11 needs roughly 18 ms, the DHT22/AM2302 only about 1.2 ms.
+    const WAKEUP_LOW: u32;
+
+    /// Convert the four validated data bytes into a fixed-point reading
+    /// expressed in tenths of a percent and tenths of a degree Celsius.
+    ///
+    /// The floating-point reading is derived from this via
+    /// [`From<RawReading> for SensorReading`](SensorReading).
+    fn convert_raw(bytes: [u8; 4]) -> RawReading;
+}
+
+/// Marker for the DHT22 / AM2302, which pack 16-bit big-endian tenths with a
+/// sign bit in the high temperature byte.
+pub struct Dht22Kind;
+
+impl SensorKind for Dht22Kind {
+    const WAKEUP_LOW: u32 = 1200;
+
+    fn convert_raw(bytes: [u8; 4]) -> RawReading {
+        let humidity_deci = ((bytes[0] as u16) << 8 | bytes[1] as u16) as i16;
+        // The MSB of the 16 temperature bits indicates negative temperatures
+        let is_negative = (bytes[2] >> 7) != 0;
+        let magnitude = (((bytes[2] & 0b0111_1111) as u16) << 8 | bytes[3] as u16) as i16;
+        let temperature_deci = if is_negative { -magnitude } else { magnitude };
+        RawReading {
+            humidity_deci,
+            temperature_deci,
+        }
+    }
+}
+
+/// The AM2301 and AM2302 are DHT22 clones and share its encoding exactly.
+pub type Am2301Kind = Dht22Kind;
+
+/// The AM2320 also transmits 16-bit big-endian tenths like the DHT22.
+pub type Am2320Kind = Dht22Kind;
+
+/// Marker for the DHT11, which sends the integer part of humidity and
+/// temperature in `bytes[0]`/`bytes[2]` and a fractional part (usually 0) in
+/// `bytes[1]`/`bytes[3]`.
+pub struct Dht11Kind;
+
+impl SensorKind for Dht11Kind {
+    const WAKEUP_LOW: u32 = 18_000;
+
+    fn convert_raw(bytes: [u8; 4]) -> RawReading {
+        let humidity_deci = bytes[0] as i16 * 10 + bytes[1] as i16;
+        let temperature_deci = bytes[2] as i16 * 10 + bytes[3] as i16;
+        RawReading {
+            humidity_deci,
+            temperature_deci,
+        }
+    }
+}
+
 /// Represents a DHT22 sensor connected to a pin.
-pub struct Dht22<Pin, Timer>
+///
+/// The `Kind` type parameter selects the sensor family and defaults to the
+/// DHT22/AM2302; use [`Dht11`] for the DHT11.
+pub struct Dht22<Pin, Timer, Kind = Dht22Kind>
 where
     Pin: IOPin,
     Timer: MicroTimer,
+    Kind: SensorKind,
 {
     pin: Pin,
     timer: Timer,
+    /// Calibration offsets applied to the raw reading, in deci-units.
+    humidity_offset: i16,
+    temperature_offset: i16,
+    /// Minimum time in microseconds that has to pass between two reads.
+    min_interval: u32,
+    /// Timestamp of the last successful read, if any.
+    last_read: Option<Microseconds>,
+    /// The last successful reading, returned while within the cooldown.
+    last_reading: Option<RawReading>,
+    kind: PhantomData<Kind>,
+}
+
+/// A [`Dht22`] configured for the DHT11 sensor.
+pub type Dht11<Pin, Timer> = Dht22<Pin, Timer, Dht11Kind>;
+
+/// A [`Dht22`] configured for the AM2301 sensor (DHT22-compatible).
+pub type Am2301<Pin, Timer> = Dht22<Pin, Timer, Am2301Kind>;
+
+/// A [`Dht22`] configured for the AM2320 sensor (DHT22-compatible).
+pub type Am2320<Pin, Timer> = Dht22<Pin, Timer, Am2320Kind>;
+
+/// A valid reading from the DHT22 sensor in fixed-point deci-units.
+///
+/// Humidity is in tenths of a percent and temperature in tenths of a degree
+/// Celsius (signed, preserving the DHT22 sign bit). This is the representation
+/// produced without any floating-point arithmetic, which keeps the decode cheap
+/// on targets without a hardware FPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RawReading {
+    pub humidity_deci: i16,
+    pub temperature_deci: i16,
 }
 
 /// A valid reading from the DHT22 sensor
+#[cfg(not(feature = "no-float"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SensorReading {
     pub humidity: f32,
     pub temperature: f32,
 }
 
-impl<Pin, Timer> Dht22<Pin, Timer>
+#[cfg(not(feature = "no-float"))]
+impl From<RawReading> for SensorReading {
+    fn from(raw: RawReading) -> Self {
+        Self {
+            humidity: raw.humidity_deci as f32 / 10.,
+            temperature: raw.temperature_deci as f32 / 10.,
+        }
+    }
+}
+
+/// Decode a frame captured as edge durations into a raw reading, without any
+/// bus interaction or per-sensor calibration.
+///
+/// `cycles` holds the `2 * 40 + 1` durations between consecutive edges (the
+/// first, the gap before the data starts, is ignored). Hardware that captures
+/// the DHT pulse train directly — e.g. the ESP RMT peripheral or an STM32 timer
+/// input-capture channel — can feed its durations here and skip the bit-banged
+/// bus timing and the critical section entirely.
+pub fn decode_raw<Kind: SensorKind>(
+    cycles: &[u32],
+) -> Result<RawReading, DhtError<core::convert::Infallible>> {
+    let mut bytes: [u8; 5] = [0; 5];
+    // Ignore first element, because the time until data transmission starts is not important
+    for (idx, _) in cycles[1..]
+        // Group the durations of the low and high voltage for each bit
+        .chunks_exact(2)
+        // Map the duration of the high voltage to a 0 or 1
+        .map(|pair| {
+            let cycles_low = pair[0];
+            let cycles_high = pair[1];
+            // use the low duration as a reference to be robust against jitter
+            cycles_low < cycles_high
+        })
+        // Count with index to know where to shift the bit
+        .enumerate()
+        // Ignore 0-bits as that is already their initial value
+        .filter(|(_, bit)| *bit)
+    {
+        let byte_idx = idx / 8;
+        let bit_idx = idx % 8;
+        bytes[byte_idx] |= 1 << (7 - bit_idx);
+    }
+    // Verify the checksum in the last byte
+    let correct = bytes[4];
+    let actual = bytes[0]
+        .wrapping_add(bytes[1])
+        .wrapping_add(bytes[2])
+        .wrapping_add(bytes[3]);
+    if actual != correct {
+        return Err(DhtError::Checksum { actual, correct });
+    }
+    Ok(Kind::convert_raw([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Decode a DHT22 frame captured as edge durations into a floating-point
+/// reading. See [`decode_raw`] for the fixed-point variant and the accepted
+/// layout, and [`decode_kind`] to decode another sensor family.
+#[cfg(not(feature = "no-float"))]
+pub fn decode(cycles: &[u32]) -> Result<SensorReading, DhtError<core::convert::Infallible>> {
+    decode_kind::<Dht22Kind>(cycles)
+}
+
+/// Decode a frame captured as edge durations into a floating-point reading for
+/// a specific sensor family. [`decode`] is the DHT22 shorthand.
+#[cfg(not(feature = "no-float"))]
+pub fn decode_kind<Kind: SensorKind>(
+    cycles: &[u32],
+) -> Result<SensorReading, DhtError<core::convert::Infallible>> {
+    decode_raw::<Kind>(cycles).map(SensorReading::from)
+}
+
+impl<Pin, Timer, Kind> Dht22<Pin, Timer, Kind>
 where
     Pin: IOPin,
     <Pin as IOPin>::DeviceError: core::fmt::Debug,
     Timer: MicroTimer,
+    Kind: SensorKind,
 {
     /// Construct a new representation of the DHT22 sensor.
     /// Construction is cheap as long as the pin and clock are cheap to move.
     pub fn new(pin: Pin, clock: Timer) -> Self {
-        Self { pin, timer: clock }
+        Self {
+            pin,
+            timer: clock,
+            humidity_offset: 0,
+            temperature_offset: 0,
+            // The DHT22 cannot be read more than once every 2 seconds.
+            min_interval: 2_000_000,
+            last_read: None,
+            last_reading: None,
+            kind: PhantomData,
+        }
+    }
+
+    /// Override the minimum time that has to pass between two reads (default 2 s).
+    /// Calls to [`read`](Self::read) within this interval return the cached
+    /// reading, or [`DhtError::TooSoon`] if none is available yet.
+    pub fn with_min_interval(mut self, interval: Microseconds) -> Self {
+        self.min_interval = interval.0;
+        self
+    }
+
+    /// Set a calibration offset added to every temperature reading to correct
+    /// systematic bias of an individual sensor.
+    ///
+    /// The offset is given in degrees Celsius on the floating-point path and in
+    /// tenths of a degree on the `no-float` path, matching the units of the
+    /// reading itself.
+    #[cfg(not(feature = "no-float"))]
+    pub fn with_temperature_offset(mut self, offset: f32) -> Self {
+        self.temperature_offset = (offset * 10.) as i16;
+        self
+    }
+
+    /// Set a calibration offset added to every humidity reading to correct
+    /// systematic bias of an individual sensor. The adjusted humidity is
+    /// clamped to the valid 0–100% range.
+    ///
+    /// The offset is given in percent on the floating-point path and in tenths
+    /// of a percent on the `no-float` path, matching the units of the reading.
+    #[cfg(not(feature = "no-float"))]
+    pub fn with_humidity_offset(mut self, offset: f32) -> Self {
+        self.humidity_offset = (offset * 10.) as i16;
+        self
+    }
+
+    /// Set a calibration offset added to every temperature reading, in tenths
+    /// of a degree Celsius.
+    #[cfg(feature = "no-float")]
+    pub fn with_temperature_offset(mut self, offset: i16) -> Self {
+        self.temperature_offset = offset;
+        self
+    }
+
+    /// Set a calibration offset added to every humidity reading, in tenths of a
+    /// percent. The adjusted humidity is clamped to the valid 0–100% range.
+    #[cfg(feature = "no-float")]
+    pub fn with_humidity_offset(mut self, offset: i16) -> Self {
+        self.humidity_offset = offset;
+        self
     }
     /// Attempt one read from the DHT22 sensor.
     /// Between subsequent reads from the same sensor at least 2 seconds should pass to avoid erratic readings.
     /// Reading to early after startup may also result in failure to read.
+    /// Calls made within the minimum interval (see [`with_min_interval`](Self::with_min_interval))
+    /// after a successful read do not query the sensor and return the cached reading instead.
+    #[cfg(not(feature = "no-float"))]
     pub fn read(&mut self) -> Result<SensorReading, DhtError<Pin::DeviceError>> {
+        self.read_raw().map(SensorReading::from)
+    }
+
+    /// Attempt one read from the sensor, returning the decoded values as
+    /// fixed-point deci-units without any floating-point arithmetic.
+    /// Calls made within the minimum interval (see [`with_min_interval`](Self::with_min_interval))
+    /// after a successful read do not query the sensor and return the cached reading instead.
+    /// Reading to early after startup may also result in failure to read.
+    pub fn read_raw(&mut self) -> Result<RawReading, DhtError<Pin::DeviceError>> {
+        // Honor the minimum inter-read interval using the same wrapping-subtraction
+        // logic as `Waiter` so a wrapped timer is handled correctly.
+        if let Some(last_read) = self.last_read {
+            let elapsed = self.timer.now().0.wrapping_sub(last_read.0);
+            if elapsed < self.min_interval {
+                return match self.last_reading {
+                    Some(reading) => Ok(reading),
+                    None => Err(DhtError::TooSoon {
+                        remaining: Microseconds(self.min_interval - elapsed),
+                    }),
+                };
+            }
+        }
+
+        // Issue the wake-up low pulse before entering the critical section. Its
+        // length has no microsecond-jitter requirement (the DHT11 even needs
+        // ~18 ms), so there is no reason to hold off all interrupts for it.
+        self.pin.set_low()?;
+        {
+            let waiter = Waiter { timer: &self.timer };
+            let _ = waiter.wait_for(|| false, Kind::WAKEUP_LOW);
+        }
+        self.pin.set_high()?;
+        self.capture_after_wake()
+    }
+
+    /// Capture and decode the sensor's response after the wake-up pulse has been
+    /// issued and the line released high.
+    ///
+    /// This is the jitter-sensitive part shared by the polled [`read_raw`](Self::read_raw)
+    /// and the async [`read_async`](Self::read_async) paths: only the capture
+    /// itself runs in the short critical section, while the long wake-up hold is
+    /// left to the caller (spun or awaited).
+    fn capture_after_wake(&mut self) -> Result<RawReading, DhtError<Pin::DeviceError>> {
         const RESPONSE_BITS: usize = 40;
         // Each bit is indicated by the two edges of the HIGH level (up, down).
         // In addition the initial down edge from the get-ready HIGH state is recorded.
         let mut cycles: [u32; 2 * RESPONSE_BITS + 1] = [0; 2 * RESPONSE_BITS + 1];
         let waiter = Waiter { timer: &self.timer };
-        // Disable interrupts while interacting with the sensor so they don't mess up the timings
+        // Disable interrupts while capturing the jitter-sensitive response
         with(|_guard| {
-            // Initial handshake
-            self.pin.set_low()?;
-            let _ = waiter.wait_for(|| false, 1200);
-            self.pin.set_high()?;
-
             // Wait for DHT22 to acknowledge the handshake with low
             if waiter.wait_for(|| self.pin.is_low(), 100).is_err() {
                 return Err(DhtError::Handshake);
@@ -147,50 +447,251 @@ where
             err
         })?;
 
-        let mut bytes: [u8; 5] = [0; 5];
-        // Ignore first element, because the time until data transmission starts is not important
-        for (idx, _) in cycles[1..]
-            // Group the durations of the low and high voltage for each bit
-            .chunks_exact(2)
-            // Map the duration of the high voltage to a 0 or 1
-            .map(|pair| {
-                let cycles_low = pair[0];
-                let cycles_high = pair[1];
-                // use the low duration as a reference to be robust against jitter
-                cycles_low < cycles_high
-            })
-            // Count with index to know where to shift the bit
-            .enumerate()
-            // Ignore 0-bits as that is already their initial value
-            .filter(|(_, bit)| *bit)
-        {
-            let byte_idx = idx / 8;
-            let bit_idx = idx % 8;
-            bytes[byte_idx] |= 1 << (7 - bit_idx);
+        let reading = self.decode_cycles(&cycles)?;
+        self.last_read = Some(self.timer.now());
+        self.last_reading = Some(reading);
+        Ok(reading)
+    }
+
+    /// Turn the `2 * 40 + 1` captured edge durations into a calibrated reading.
+    ///
+    /// This is the pure decode stage shared by the polled [`read_raw`](Self::read_raw)
+    /// and the edge-capture backend: chunk the durations into bit pairs, compare
+    /// the low and high halves, verify the checksum and apply calibration. It is
+    /// free of any bus interaction, so it can be exercised deterministically.
+    fn decode_cycles(&self, cycles: &[u32]) -> Result<RawReading, DhtError<Pin::DeviceError>> {
+        // Reuse the pure decoder, then apply this sensor's calibration.
+        let mut reading = decode_raw::<Kind>(cycles).map_err(DhtError::widen)?;
+        reading.temperature_deci = reading.temperature_deci.saturating_add(self.temperature_offset);
+        reading.humidity_deci = reading
+            .humidity_deci
+            .saturating_add(self.humidity_offset)
+            .clamp(0, 1000);
+        Ok(reading)
+    }
+
+    /// Decode a reading from externally captured edge timestamps instead of the
+    /// inline polling loop.
+    ///
+    /// `edges` holds the absolute timestamps of the 82 level changes that bound
+    /// a frame (the edge ending the sensor's acknowledge low, the edge ending
+    /// its ready high, and then the rising and falling edge of the high phase of
+    /// each of the 40 bits — `2 * 40 + 2` timestamps yield the
+    /// `2 * 40 + 1` durations). The timestamps are differenced into the same
+    /// `cycles` durations the polled path produces and fed through the shared
+    /// [`decode_cycles`](Self::decode_cycles) stage, so a capture backend fed
+    /// from an edge-interrupt ISR can be unit-tested without hardware. A shorter
+    /// slice is not rejected: the missing trailing cycles stay at 0 and decode
+    /// as 0 bits, so it only fails if the checksum then no longer matches (an
+    /// empty slice decodes as an all-zero frame, whose checksum is valid).
+    #[cfg(feature = "edge-capture")]
+    pub fn read_from_edges(
+        &mut self,
+        edges: &[Microseconds],
+    ) -> Result<RawReading, DhtError<Pin::DeviceError>> {
+        const RESPONSE_BITS: usize = 40;
+        let mut cycles: [u32; 2 * RESPONSE_BITS + 1] = [0; 2 * RESPONSE_BITS + 1];
+        for (cycle, pair) in cycles.iter_mut().zip(edges.windows(2)) {
+            *cycle = pair[1].0.wrapping_sub(pair[0].0);
+        }
+        let reading = self.decode_cycles(&cycles)?;
+        self.last_read = Some(self.timer.now());
+        self.last_reading = Some(reading);
+        Ok(reading)
+    }
+}
+
+/// Input pin whose edges can be captured by an interrupt service routine and
+/// timestamped against a [`MicroTimer`], as an alternative to busy-polling with
+/// all interrupts disabled.
+///
+/// A backend arms the edge interrupt, collects timestamps into an ISR-fed ring
+/// buffer and finally hands the drained buffer to
+/// [`Dht22::read_from_edges`](Dht22::read_from_edges).
+#[cfg(feature = "edge-capture")]
+pub trait EdgeCapture {
+    type DeviceError;
+    /// Arm an interrupt that fires on every edge (rising and falling).
+    fn arm(&mut self) -> Result<(), Self::DeviceError>;
+    /// Disarm the edge interrupt.
+    fn disarm(&mut self) -> Result<(), Self::DeviceError>;
+    /// Drain the timestamps captured since the last call into `out`, returning
+    /// the number of edges written.
+    fn drain(&mut self, out: &mut [Microseconds]) -> usize;
+}
+
+/// An async delay source used by [`Dht22::read_async`] to yield to the executor
+/// during the long waits of a read cycle.
+///
+/// This mirrors `embedded_hal_async::delay::DelayNs` so a HAL timer can back it
+/// directly, while keeping the bespoke-trait style of [`IOPin`]/[`MicroTimer`].
+#[cfg(feature = "async")]
+pub trait AsyncDelay {
+    /// Yield to the executor for at least `us` microseconds.
+    fn delay_us(&mut self, us: u32) -> impl core::future::Future<Output = ()>;
+}
+
+/// An input pin that can await the next level change, mirroring
+/// `embedded_hal_async::digital::Wait`. Used by
+/// [`Dht22::read_async_edges`](Dht22::read_async_edges) to timestamp the frame
+/// without disabling interrupts.
+#[cfg(feature = "async")]
+pub trait WaitForEdge {
+    type DeviceError;
+    /// Await the next edge (rising or falling) on the line. Implementations are
+    /// expected to cancel the wait after a sensible per-edge timeout and return
+    /// an error, which is surfaced as [`DhtError::Handshake`] on the two
+    /// handshake edges and as [`DhtError::Timeout`] on the data edges.
+    fn wait_for_edge(
+        &mut self,
+    ) -> impl core::future::Future<Output = Result<(), Self::DeviceError>>;
+}
+
+#[cfg(all(feature = "async", not(feature = "no-float")))]
+impl<Pin, Timer, Kind> Dht22<Pin, Timer, Kind>
+where
+    Pin: IOPin,
+    <Pin as IOPin>::DeviceError: core::fmt::Debug,
+    Timer: MicroTimer,
+    Kind: SensorKind,
+{
+    /// Perform a read without monopolizing the executor.
+    ///
+    /// The bit timing during the ~5 ms exchange is too fast to await each edge
+    /// individually, so the actual capture still runs in the short blocking
+    /// critical section shared with [`read`](Self::read). The long waits — the
+    /// *remaining* inter-read cooldown (see
+    /// [`with_min_interval`](Self::with_min_interval)) and the wake-up low pulse
+    /// that follows it — are awaited instead of spun, letting other tasks run.
+    pub async fn read_async(
+        &mut self,
+        delay: &mut impl AsyncDelay,
+    ) -> Result<SensorReading, DhtError<Pin::DeviceError>> {
+        // Await only the time left on the cooldown, not a fixed interval.
+        if let Some(last_read) = self.last_read {
+            let elapsed = self.timer.now().0.wrapping_sub(last_read.0);
+            if elapsed < self.min_interval {
+                delay.delay_us(self.min_interval - elapsed).await;
+            }
         }
-        // Verify the checksum in the last byte
-        let correct = bytes[4];
-        let actual = bytes[0]
-            .wrapping_add(bytes[1])
-            .wrapping_add(bytes[2])
-            .wrapping_add(bytes[3]);
-        if actual != correct {
-            return Err(DhtError::Checksum { actual, correct });
+        // Wake-up pulse: await the mandatory low hold rather than busy-spinning
+        // it, then capture the response in the shared critical section.
+        self.pin.set_low()?;
+        delay.delay_us(Kind::WAKEUP_LOW).await;
+        self.pin.set_high()?;
+        self.capture_after_wake().map(SensorReading::from)
+    }
+
+    /// Read the sensor without a global critical section by awaiting every edge.
+    ///
+    /// After sending the start pulse the ~5 ms exchange is captured by awaiting
+    /// each of the 81 level changes on `wait` and timestamping it against the
+    /// [`MicroTimer`], instead of spin-polling with interrupts disabled. The
+    /// captured durations are fed through the same
+    /// [`decode_cycles`](Self::decode_cycles) stage as the polled path. A `wait`
+    /// that fails on one of the two handshake edges surfaces as
+    /// [`DhtError::Handshake`], one that fails on a data edge as
+    /// [`DhtError::Timeout`].
+    pub async fn read_async_edges<W>(
+        &mut self,
+        wait: &mut W,
+        delay: &mut impl AsyncDelay,
+    ) -> Result<SensorReading, DhtError<Pin::DeviceError>>
+    where
+        W: WaitForEdge,
+    {
+        const RESPONSE_BITS: usize = 40;
+        // Send the start pulse, yielding to the executor during the long waits.
+        self.pin.set_low()?;
+        delay.delay_us(Kind::WAKEUP_LOW).await;
+        self.pin.set_high()?;
+
+        // Handshake: the sensor pulls the line low and then releases it high.
+        wait.wait_for_edge().await.map_err(|_| DhtError::Handshake)?;
+        wait.wait_for_edge().await.map_err(|_| DhtError::Handshake)?;
+
+        // Timestamp each data edge, reconstructing the same `cycles` durations.
+        let mut cycles: [u32; 2 * RESPONSE_BITS + 1] = [0; 2 * RESPONSE_BITS + 1];
+        let mut prev = self.timer.now();
+        for cycle in &mut cycles {
+            wait.wait_for_edge()
+                .await
+                .map_err(|_| DhtError::Timeout(Microseconds(self.timer.now().0.wrapping_sub(prev.0))))?;
+            let now = self.timer.now();
+            *cycle = now.0.wrapping_sub(prev.0);
+            prev = now;
         }
-        let humidity = (((bytes[0] as u32) << 8 | bytes[1] as u32) as f32) / 10.;
-        // The MSB of the 16 temperature bits indicates negative temperatures
-        let is_negative = (bytes[2] >> 7) != 0;
-        bytes[2] &= 0b0111_1111;
-        let temperature = (((bytes[2] as u32) << 8 | bytes[3] as u32) as f32) / 10.;
-        let temperature = if is_negative {
-            -1. * temperature
-        } else {
-            temperature
-        };
-        Ok(SensorReading {
-            humidity,
-            temperature,
-        })
+
+        let reading = self.decode_cycles(&cycles)?;
+        self.last_read = Some(self.timer.now());
+        self.last_reading = Some(reading);
+        Ok(reading.into())
+    }
+}
+
+// The async support shares the `AsyncDelay`/`WaitForEdge` traits regardless of
+// the float path, but its read methods return `SensorReading` and are therefore
+// only available when the floating-point conversion is compiled in.
+
+/// Blanket impl letting any `embedded-hal` digital pin be used as an [`IOPin`]
+/// without a hand-written newtype, mapping the HAL error into
+/// [`DhtError::DeviceError`]. The pin has to expose the same error type for
+/// input and output, which is the case for every HAL's GPIO type.
+#[cfg(feature = "embedded-hal")]
+impl<T, E> IOPin for T
+where
+    T: embedded_hal::digital::v2::InputPin<Error = E>
+        + embedded_hal::digital::v2::OutputPin<Error = E>,
+{
+    type DeviceError = E;
+
+    fn set_low(&mut self) -> Result<(), Self::DeviceError> {
+        embedded_hal::digital::v2::OutputPin::set_low(self)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::DeviceError> {
+        embedded_hal::digital::v2::OutputPin::set_high(self)
+    }
+
+    fn is_low(&self) -> bool {
+        // A failed read is reported as "not low", surfacing later as a handshake
+        // or timeout error rather than a panic.
+        embedded_hal::digital::v2::InputPin::is_low(self).unwrap_or(false)
+    }
+
+    fn is_high(&self) -> bool {
+        embedded_hal::digital::v2::InputPin::is_high(self).unwrap_or(false)
+    }
+}
+
+/// Adapts a free-running hardware counter into a [`MicroTimer`], so users need
+/// no hand-written newtype around their HAL timer.
+///
+/// `embedded-hal` has no monotonic-clock trait (its timer traits count down and
+/// cannot be read), so the portable way to obtain "now" is the closure reading
+/// the HAL's counter peripheral — which is exactly what this wraps:
+///
+/// ```ignore
+/// # #[cfg(feature = "embedded-hal")] {
+/// // `pin` is the HAL's GPIO, `counter` reads a free-running microsecond timer.
+/// let mut sensor = dht22::Dht22::new(pin, HalTimer(|| counter.now_micros()));
+/// # }
+/// ```
+///
+/// The wrapped closure must return the current tick count in microseconds and
+/// is allowed to wrap.
+#[cfg(feature = "embedded-hal")]
+pub struct HalTimer<F>(pub F)
+where
+    F: Fn() -> u32;
+
+#[cfg(feature = "embedded-hal")]
+impl<F> MicroTimer for HalTimer<F>
+where
+    F: Fn() -> u32,
+{
+    fn now(&self) -> Microseconds {
+        Microseconds((self.0)())
     }
 }
 
@@ -226,3 +727,66 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build the `2 * 40 + 1` edge durations a frame of `bytes` would produce.
+    /// The high half is long for a 1 bit and short for a 0 bit; the low half is
+    /// the constant reference the decoder compares against.
+    fn cycles_for(bytes: [u8; 5]) -> [u32; 81] {
+        let mut cycles = [0u32; 81];
+        // The first duration is the gap before transmission and is ignored.
+        cycles[0] = 80;
+        for i in 0..40 {
+            let bit = (bytes[i / 8] >> (7 - (i % 8))) & 1;
+            cycles[1 + i * 2] = 50;
+            cycles[1 + i * 2 + 1] = if bit == 1 { 70 } else { 26 };
+        }
+        cycles
+    }
+
+    #[test]
+    fn decodes_dht22_frame() {
+        // 45.2 %RH, 23.4 °C
+        let bytes = [0x01, 0xC4, 0x00, 0xEA, 0xAF];
+        let raw = decode_raw::<Dht22Kind>(&cycles_for(bytes)).unwrap();
+        assert_eq!(raw.humidity_deci, 452);
+        assert_eq!(raw.temperature_deci, 234);
+    }
+
+    #[test]
+    fn decodes_negative_dht22_temperature() {
+        // 50.0 %RH, -10.0 °C (sign bit set in bytes[2])
+        let bytes = [0x01, 0xF4, 0x80, 0x64, 0xD9];
+        let raw = decode_raw::<Dht22Kind>(&cycles_for(bytes)).unwrap();
+        assert_eq!(raw.humidity_deci, 500);
+        assert_eq!(raw.temperature_deci, -100);
+        #[cfg(not(feature = "no-float"))]
+        {
+            let reading = decode(&cycles_for(bytes)).unwrap();
+            assert_eq!(reading.temperature, -10.0);
+            assert_eq!(reading.humidity, 50.0);
+        }
+    }
+
+    #[test]
+    fn decodes_dht11_frame() {
+        // 45 %RH, 23 °C with zero fractional bytes
+        let bytes = [45, 0, 23, 0, 68];
+        let raw = decode_raw::<Dht11Kind>(&cycles_for(bytes)).unwrap();
+        assert_eq!(raw.humidity_deci, 450);
+        assert_eq!(raw.temperature_deci, 230);
+    }
+
+    #[test]
+    fn rejects_checksum_mismatch() {
+        let mut bytes = [0x01, 0xC4, 0x00, 0xEA, 0xAF];
+        bytes[4] = 0x00;
+        assert!(matches!(
+            decode_raw::<Dht22Kind>(&cycles_for(bytes)),
+            Err(DhtError::Checksum { .. })
+        ));
+    }
+}